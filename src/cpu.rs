@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+// Copyright Julian Ganz 2024
+//! Utilities for handling `/proc/stat`
+
+use std::collections::HashMap;
+
+use crate::read::BufProcessor;
+use crate::source::{Source, WantsProcessing};
+
+/// CPU utilization derived from successive reads of `/proc/stat`
+///
+/// `/proc/stat` reports cumulative tick counters since boot, so utilization
+/// has to be derived from the delta between two reads. This type retains the
+/// previous sample for each reported line (the aggregate `cpu` line as well
+/// as the per-core `cpu0`, `cpu1`, ... lines) in order to compute that delta.
+#[derive(Default)]
+pub struct Stat(HashMap<String, Sample>);
+
+impl Stat {
+    /// Get the percentage of time spent busy for the given core
+    ///
+    /// `core` is the label as found in `/proc/stat`, e.g. `"cpu"` for the
+    /// aggregate over all cores or `"cpu0"` for a specific one.
+    pub fn usage(&self, core: &str) -> Option<f32> {
+        self.0.get(core).and_then(|s| s.percent)
+    }
+}
+
+impl Source for Stat {
+    type Value = Self;
+
+    type Borrow<'a> = &'a Self::Value;
+
+    fn value(&self) -> Option<Self::Borrow<'_>> {
+        Some(self)
+    }
+}
+
+impl WantsProcessing for Stat {}
+
+impl BufProcessor for Stat {
+    fn process(&mut self, buf: &[u8]) {
+        buf.split(|c| *c == b'\n')
+            .map(std::str::from_utf8)
+            .filter_map(Result::ok)
+            .filter_map(|l| l.split_once(char::is_whitespace))
+            .filter(|(label, _)| label.starts_with("cpu"))
+            .for_each(|(label, rest)| {
+                let values: Vec<u64> = rest
+                    .split_ascii_whitespace()
+                    .filter_map(|f| f.parse().ok())
+                    .collect();
+                if values.len() < 4 {
+                    return;
+                }
+
+                let total = values.iter().sum();
+                let idle = values[3] + values.get(4).copied().unwrap_or_default();
+
+                self.0
+                    .entry(label.to_owned())
+                    .or_insert(Sample {
+                        prev: None,
+                        percent: None,
+                    })
+                    .update(total, idle);
+            });
+    }
+}
+
+/// A single sample for a `/proc/stat` CPU line
+struct Sample {
+    prev: Option<(u64, u64)>,
+    percent: Option<f32>,
+}
+
+impl Sample {
+    /// Update this sample with a new total/idle reading
+    ///
+    /// The very first update (i.e. one for a freshly inserted [Sample]) can
+    /// not yield a percentage, as there is no prior sample to compute a delta
+    /// against.
+    fn update(&mut self, total: u64, idle: u64) {
+        self.percent = self.prev.map(|(prev_total, prev_idle)| {
+            let delta_total = total.saturating_sub(prev_total);
+            let delta_idle = idle.saturating_sub(prev_idle);
+            delta_total.saturating_sub(delta_idle) as f32 / delta_total.max(1) as f32 * 100.
+        });
+        self.prev = Some((total, idle));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_sample_yields_none() {
+        let mut stat = Stat::default();
+        stat.process(b"cpu  100 0 0 900 0 0 0 0 0 0\n");
+        assert_eq!(stat.usage("cpu"), None);
+    }
+
+    #[test]
+    fn second_sample_yields_percentage() {
+        let mut stat = Stat::default();
+        stat.process(b"cpu  100 0 0 900 0 0 0 0 0 0\n");
+        stat.process(b"cpu  150 0 0 950 0 0 0 0 0 0\n");
+        assert_eq!(stat.usage("cpu"), Some(50.));
+    }
+
+    #[test]
+    fn per_core_lines_are_tracked_independently() {
+        let mut stat = Stat::default();
+        stat.process(b"cpu  100 0 0 900 0 0 0 0 0 0\ncpu0 50 0 0 450 0 0 0 0 0 0\n");
+        stat.process(b"cpu  150 0 0 950 0 0 0 0 0 0\ncpu0 150 0 0 450 0 0 0 0 0 0\n");
+        assert_eq!(stat.usage("cpu"), Some(50.));
+        assert_eq!(stat.usage("cpu0"), Some(100.));
+    }
+
+    #[test]
+    fn unknown_core_yields_none() {
+        let stat = Stat::default();
+        assert_eq!(stat.usage("cpu3"), None);
+    }
+}
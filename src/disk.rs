@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+// Copyright Julian Ganz 2024
+//! Utilities for polling filesystem usage via `statvfs(2)`
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::read::Poller;
+use crate::source::{Source, WantsProcessing};
+use crate::Instant;
+
+/// Filesystem usage, as reported by `statvfs(2)`
+#[derive(Copy, Clone)]
+pub struct Usage {
+    free: u64,
+    total: u64,
+}
+
+impl Usage {
+    /// Get the free space, in bytes
+    pub fn free(&self) -> u64 {
+        self.free
+    }
+
+    /// Get the total space, in bytes
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Get the used space, in bytes
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.free)
+    }
+
+    /// Get the percentage of space used
+    pub fn percent_used(&self) -> f64 {
+        100. * self.used() as f64 / self.total as f64
+    }
+}
+
+/// A [Poller] periodically calling `statvfs(2)` on a mount point
+pub struct Mount {
+    path: PathBuf,
+    interval: Duration,
+    data: Option<(Usage, Instant)>,
+}
+
+impl Mount {
+    /// Create a new [Mount], polling the given `path` on the given `interval`
+    pub fn new(path: impl Into<PathBuf>, interval: Duration) -> Self {
+        Self {
+            path: path.into(),
+            interval,
+            data: None,
+        }
+    }
+}
+
+impl Source for Mount {
+    type Value = Usage;
+
+    fn value(&self) -> Option<Self::Borrow<'_>> {
+        self.data.map(|(u, _)| u)
+    }
+}
+
+impl Poller for Mount {
+    fn poll(&mut self) {
+        self.data = statvfs(&self.path).ok().map(|u| (u, Instant::now()));
+    }
+}
+
+impl WantsProcessing for Mount {
+    fn wants_processing(&self, before: Instant) -> bool {
+        self.data
+            .as_ref()
+            .map(|(_, l)| before.duration_since(*l) >= self.interval)
+            .unwrap_or(true)
+    }
+}
+
+/// Retrieve filesystem [Usage] for the given path via `statvfs(2)`
+fn statvfs(path: &std::path::Path) -> Result<Usage> {
+    let stat = rustix::fs::statvfs(path)
+        .with_context(|| format!("Could not stat filesystem at {}", path.display()))?;
+    let free = stat.f_bavail * stat.f_frsize;
+    let total = stat.f_blocks * stat.f_frsize;
+    Ok(Usage { free, total })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn free_and_total() {
+        let usage = Usage {
+            free: 40,
+            total: 100,
+        };
+        assert_eq!(usage.free(), 40);
+        assert_eq!(usage.total(), 100);
+    }
+
+    #[test]
+    fn used_is_total_minus_free() {
+        let usage = Usage {
+            free: 40,
+            total: 100,
+        };
+        assert_eq!(usage.used(), 60);
+    }
+
+    #[test]
+    fn percent_used() {
+        let usage = Usage {
+            free: 40,
+            total: 100,
+        };
+        assert_eq!(usage.percent_used(), 60.);
+    }
+
+    #[test]
+    fn percent_used_zero_total_is_nan() {
+        let usage = Usage { free: 0, total: 0 };
+        assert!(usage.percent_used().is_nan());
+    }
+}
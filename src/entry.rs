@@ -3,7 +3,7 @@
 //! Entries
 
 use std::fmt;
-use std::ops::{Div, Mul};
+use std::ops::{Div, Mul, Rem};
 
 use crate::read::Ref;
 use crate::scale;
@@ -36,14 +36,44 @@ pub trait Entry: Sized + 'static {
     }
 
     /// Transform this entry into one with automatic scaling
+    ///
+    /// The value is scaled up as long as it stays above `min_value`. See
+    /// [Entry::autoscaled_bounded] for a variant that also scales *down*
+    /// into sub-unit prefixes for scales supporting [scale::Scale::step_down].
     fn autoscaled<V, S: scale::Scale>(self, min_value: V, scale: S) -> AutoScaled<Self, S, V> {
         AutoScaled {
             entry: self,
             scale,
             min_value,
+            max_value: None,
         }
     }
 
+    /// Transform this entry into one with automatic bidirectional scaling
+    ///
+    /// Like [Entry::autoscaled], but the value is also scaled *down* into
+    /// sub-unit prefixes (e.g. [scale::SiScale]'s `m`, `µ`, `n`, `p`) when it
+    /// would otherwise stay below `min_value`, stopping once it would exceed
+    /// `max_value`.
+    fn autoscaled_bounded<V, S: scale::Scale>(
+        self,
+        min_value: V,
+        max_value: V,
+        scale: S,
+    ) -> AutoScaled<Self, S, V> {
+        AutoScaled {
+            entry: self,
+            scale,
+            min_value,
+            max_value: Some(max_value),
+        }
+    }
+
+    /// Transform this entry into a composite (cascading-unit) duration
+    fn as_composite_duration<S: scale::Scale>(self, scale: S) -> CompositeDuration<Self, S> {
+        CompositeDuration { entry: self, scale }
+    }
+
     /// Transform this entry into a [fmt::Display]
     fn into_fmt(self) -> Box<dyn fmt::Display> {
         use fmt::Display;
@@ -102,6 +132,14 @@ impl Entry for Option<f32> {
     }
 }
 
+impl Entry for Option<u64> {
+    type Display<'a> = u64;
+
+    fn display(&self) -> Option<Self::Display<'_>> {
+        *self
+    }
+}
+
 /// Create an [Entry] mapping a [Source]
 pub fn mapped<S, F, D>(source: Ref<S>, func: F) -> impl for<'a> Entry<Display<'a> = D>
 where
@@ -179,33 +217,101 @@ impl fmt::Display for EntriesDisplay {
 }
 
 /// Entry displaying the local date and time
+///
+/// An optional strftime(3)-style format string can be supplied via
+/// [LocalTime::with_format]. Without one, a fixed `%Y-%m-%d %H:%M:%S` layout
+/// is used.
 #[derive(Default)]
-pub struct LocalTime;
+pub struct LocalTime(Option<std::ffi::CString>);
+
+impl LocalTime {
+    /// Create a [LocalTime] entry using a custom strftime(3) format
+    pub fn with_format(format: impl Into<std::ffi::CString>) -> Self {
+        Self(Some(format.into()))
+    }
+}
 
 impl Entry for LocalTime {
     type Display<'a> = DateTime;
 
     fn display(&self) -> Option<Self::Display<'_>> {
         let time = unsafe { *libc::localtime(&libc::time(std::ptr::null_mut())) };
-        Some(DateTime(time))
+        Some(DateTime {
+            tm: time,
+            format: self.0.clone(),
+        })
+    }
+}
+
+/// Entry displaying the date and time in UTC
+///
+/// The counterpart to [LocalTime], selected by a leading `utc` sub-spec of
+/// the `datetime` spec.
+#[derive(Default)]
+pub struct UtcTime(Option<std::ffi::CString>);
+
+impl UtcTime {
+    /// Create a [UtcTime] entry using a custom strftime(3) format
+    pub fn with_format(format: impl Into<std::ffi::CString>) -> Self {
+        Self(Some(format.into()))
+    }
+}
+
+impl Entry for UtcTime {
+    type Display<'a> = DateTime;
+
+    fn display(&self) -> Option<Self::Display<'_>> {
+        let time = unsafe { *libc::gmtime(&libc::time(std::ptr::null_mut())) };
+        Some(DateTime {
+            tm: time,
+            format: self.0.clone(),
+        })
     }
 }
 
 /// Printable date and time
-pub struct DateTime(libc::tm);
+pub struct DateTime {
+    tm: libc::tm,
+    format: Option<std::ffi::CString>,
+}
 
 impl fmt::Display for DateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
-            self.0.tm_year + 1900,
-            self.0.tm_mon + 1,
-            self.0.tm_mday,
-            self.0.tm_hour,
-            self.0.tm_min,
-            self.0.tm_sec,
-        )
+        let Some(format) = &self.format else {
+            return write!(
+                f,
+                "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+                self.tm.tm_year + 1900,
+                self.tm.tm_mon + 1,
+                self.tm.tm_mday,
+                self.tm.tm_hour,
+                self.tm.tm_min,
+                self.tm.tm_sec,
+            );
+        };
+
+        // `strftime` returns `0` both on truncation and for formats that
+        // legitimately produce an empty string, so an empty format is
+        // special-cased and any other failure is retried with a larger
+        // buffer before giving up.
+        let mut capacity = 64;
+        while capacity <= 4096 {
+            let mut buf = vec![0u8; capacity];
+            let len = unsafe {
+                libc::strftime(
+                    buf.as_mut_ptr() as *mut libc::c_char,
+                    buf.len(),
+                    format.as_ptr(),
+                    &self.tm,
+                )
+            };
+            if len > 0 || format.as_bytes().is_empty() {
+                return f.write_str(std::str::from_utf8(&buf[..len]).unwrap_or_default());
+            }
+            capacity *= 4;
+        }
+
+        OptionDisplay::<&str>(None).fmt(f)
     }
 }
 
@@ -272,21 +378,57 @@ pub struct AutoScaled<E: Entry, S: scale::Scale, T> {
     entry: E,
     scale: S,
     min_value: T,
+    max_value: Option<T>,
 }
 
 impl<E, S, T> Entry for AutoScaled<E, S, T>
 where
     E: Entry,
-    for<'a> E::Display<'a>: Div<T> + From<<E::Display<'a> as Div<T>>::Output> + PartialOrd<T>,
+    for<'a> E::Display<'a>: Div<T>
+        + From<<E::Display<'a> as Div<T>>::Output>
+        + Mul<T>
+        + From<<E::Display<'a> as Mul<T>>::Output>
+        + PartialOrd<T>
+        + Copy,
     S: scale::Scale + fmt::Display + 'static,
     T: Mul<T, Output = T> + From<u16> + Copy + 'static,
 {
     type Display<'a> = scale::Scaled<E::Display<'a>, S>;
 
+    fn display(&self) -> Option<Self::Display<'_>> {
+        self.entry.display().map(|d| {
+            let scaled = Self::Display::new(d, self.scale);
+            match self.max_value {
+                Some(max_value) => scaled.auto_scale(self.min_value, max_value),
+                None => scaled.max_scale(self.min_value),
+            }
+        })
+    }
+}
+
+/// An [Entry] decomposing a value into cascading units
+pub struct CompositeDuration<E: Entry, S: scale::Scale> {
+    entry: E,
+    scale: S,
+}
+
+impl<E, S> Entry for CompositeDuration<E, S>
+where
+    E: Entry,
+    for<'a> E::Display<'a>: Copy
+        + PartialOrd<u64>
+        + Div<u64>
+        + From<<E::Display<'a> as Div<u64>>::Output>
+        + Rem<u64>
+        + From<<E::Display<'a> as Rem<u64>>::Output>,
+    S: scale::Scale + scale::CompositeUnit + 'static,
+{
+    type Display<'a> = scale::Composite<E::Display<'a>, S>;
+
     fn display(&self) -> Option<Self::Display<'_>> {
         self.entry
             .display()
-            .map(|d| Self::Display::new(d, self.scale).max_scale(self.min_value))
+            .map(|d| scale::Composite::new(d, self.scale))
     }
 }
 
@@ -310,6 +452,58 @@ mod tests {
 
     use std::f32::consts::PI;
 
+    /// Build a [libc::tm] for 2024-03-05 13:07:09 (a Tuesday)
+    fn sample_tm() -> libc::tm {
+        let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+        tm.tm_year = 2024 - 1900;
+        tm.tm_mon = 2;
+        tm.tm_mday = 5;
+        tm.tm_hour = 13;
+        tm.tm_min = 7;
+        tm.tm_sec = 9;
+        tm.tm_wday = 2;
+        tm
+    }
+
+    #[test]
+    fn datetime_default_format() {
+        let dt = DateTime {
+            tm: sample_tm(),
+            format: None,
+        };
+        assert_eq!(dt.to_string(), "2024-03-05 13:07:09")
+    }
+
+    #[test]
+    fn datetime_custom_format() {
+        let dt = DateTime {
+            tm: sample_tm(),
+            format: Some(std::ffi::CString::new("%H:%M").unwrap()),
+        };
+        assert_eq!(dt.to_string(), "13:07")
+    }
+
+    #[test]
+    fn datetime_empty_format() {
+        let dt = DateTime {
+            tm: sample_tm(),
+            format: Some(std::ffi::CString::new("").unwrap()),
+        };
+        assert_eq!(dt.to_string(), "")
+    }
+
+    #[test]
+    fn datetime_format_grows_past_initial_buffer() {
+        // 30 repetitions of a 4-byte-or-more expansion push the rendered
+        // string well past the initial 64 byte buffer.
+        let format = "%Y-%m-%d ".repeat(30);
+        let dt = DateTime {
+            tm: sample_tm(),
+            format: Some(std::ffi::CString::new(format).unwrap()),
+        };
+        assert_eq!(dt.to_string(), "2024-03-05 ".repeat(30))
+    }
+
     #[test]
     fn entry_display_smoke() {
         let entries: EntriesDisplay = vec![
@@ -385,6 +579,51 @@ mod tests {
         assert_eq!(s, "3.14ki")
     }
 
+    #[test]
+    fn composite_duration_smoke() {
+        let s = Some(3723u64)
+            .as_composite_duration(scale::Duration::default())
+            .into_fmt()
+            .to_string();
+        assert_eq!(s, "1h02m03s")
+    }
+
+    #[test]
+    fn composite_duration_zero() {
+        let s = Some(0u64)
+            .as_composite_duration(scale::Duration::default())
+            .into_fmt()
+            .to_string();
+        assert_eq!(s, "0s")
+    }
+
+    #[test]
+    fn composite_duration_none() {
+        let s = None::<u64>
+            .as_composite_duration(scale::Duration::default())
+            .into_fmt()
+            .to_string();
+        assert_eq!(s, "???")
+    }
+
+    #[test]
+    fn autoscaled_bounded_down() {
+        let s = Some(0.000004f32)
+            .autoscaled_bounded(1.5, 1000., scale::SiScale::default())
+            .into_fmt()
+            .to_string();
+        assert_eq!(s, "4µ")
+    }
+
+    #[test]
+    fn autoscaled_bounded_up() {
+        let s = Some(4_000_000f32)
+            .autoscaled_bounded(1.5, 1000., scale::SiScale::default())
+            .into_fmt()
+            .to_string();
+        assert_eq!(s, "4M")
+    }
+
     #[test]
     fn with_unit_smoke() {
         let s = Some(5).with_unit("zurakos").into_fmt().to_string();
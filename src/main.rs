@@ -4,17 +4,25 @@
 use anyhow::{Context, Error, Result};
 use rustix::{io::Errno, time};
 
+use crate::source::WantsProcessing;
+
+mod cpu;
+mod disk;
 mod entry;
+mod net;
+mod power;
 mod read;
 mod scale;
 mod source;
 mod spec;
+mod thermal;
 
 fn main() -> Result<()> {
     use std::io::Write;
 
     let mut reads: Vec<read::Item> = Default::default();
-    let entries: entry::EntriesDisplay = spec::entries(&mut reads)
+    let mut polls: Vec<read::Poll> = Default::default();
+    let entries: entry::EntriesDisplay = spec::entries(&mut reads, &mut polls)
         .context("Could not parse entry specifications")?
         .into();
     let mut builder = io_uring::IoUring::builder();
@@ -45,6 +53,12 @@ fn main() -> Result<()> {
         ring.submit_and_dispatch()
             .context("Could not dispatch read items")?;
 
+        let now = std::time::Instant::now();
+        polls
+            .iter()
+            .filter(|p| p.wants_processing(now))
+            .for_each(read::Poll::poll);
+
         writeln!(output_buffer, "{entries}").context("Could not format line")?;
         std::io::stdout()
             .write_all(output_buffer.as_ref())
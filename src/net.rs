@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+// Copyright Julian Ganz 2024
+//! Utilities for handling `/proc/net/dev`
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+
+use crate::read::BufProcessor;
+use crate::source::{Source, WantsProcessing};
+use crate::Instant;
+
+/// Get the names of all network interfaces listed in `/proc/net/dev`
+pub fn interfaces() -> Result<Vec<String>> {
+    let content =
+        std::fs::read_to_string("/proc/net/dev").context("Could not read /proc/net/dev")?;
+    let ifaces = content
+        .lines()
+        .skip(2)
+        .filter_map(|l| l.split_once(':'))
+        .map(|(iface, _)| iface.trim().to_owned())
+        .collect();
+    Ok(ifaces)
+}
+
+/// Network interface throughput derived from successive reads of
+/// `/proc/net/dev`
+///
+/// `/proc/net/dev` reports cumulative byte counters since the interface was
+/// brought up, so a rate has to be derived from the delta between two reads.
+/// This type retains the previous sample (including the time it was taken)
+/// for each reported interface in order to compute that delta.
+#[derive(Default)]
+pub struct Stat(HashMap<String, Iface>);
+
+impl Stat {
+    /// Get the receive rate, in bytes/second, for the given interface
+    pub fn rx_rate(&self, iface: &str) -> Option<f32> {
+        self.0.get(iface).and_then(|i| i.rx_rate)
+    }
+
+    /// Get the transmit rate, in bytes/second, for the given interface
+    pub fn tx_rate(&self, iface: &str) -> Option<f32> {
+        self.0.get(iface).and_then(|i| i.tx_rate)
+    }
+}
+
+impl Source for Stat {
+    type Value = Self;
+
+    type Borrow<'a> = &'a Self::Value;
+
+    fn value(&self) -> Option<Self::Borrow<'_>> {
+        Some(self)
+    }
+}
+
+impl WantsProcessing for Stat {}
+
+impl BufProcessor for Stat {
+    fn process(&mut self, buf: &[u8]) {
+        let now = Instant::now();
+        buf.split(|c| *c == b'\n')
+            .skip(2)
+            .map(std::str::from_utf8)
+            .filter_map(Result::ok)
+            .filter_map(|l| l.split_once(':'))
+            .for_each(|(iface, rest)| {
+                let values: Vec<u64> = rest
+                    .split_ascii_whitespace()
+                    .filter_map(|f| f.parse().ok())
+                    .collect();
+                let (Some(&rx), Some(&tx)) = (values.first(), values.get(8)) else {
+                    return;
+                };
+
+                self.0
+                    .entry(iface.trim().to_owned())
+                    .or_insert(Iface {
+                        prev: None,
+                        rx_rate: None,
+                        tx_rate: None,
+                    })
+                    .update(rx, tx, now);
+            });
+    }
+}
+
+/// A single sample for a `/proc/net/dev` interface line
+struct Iface {
+    prev: Option<(u64, u64, Instant)>,
+    rx_rate: Option<f32>,
+    tx_rate: Option<f32>,
+}
+
+impl Iface {
+    /// Update this sample with a new rx/tx byte count reading
+    ///
+    /// The very first update (i.e. one for a freshly inserted [Iface]) can
+    /// not yield a rate, as there is no prior sample to compute a delta
+    /// against.
+    fn update(&mut self, rx: u64, tx: u64, now: Instant) {
+        let rates = self.prev.and_then(|(prev_rx, prev_tx, then)| {
+            let elapsed = now.duration_since(then).as_secs_f32();
+            (elapsed > 0.).then(|| {
+                (
+                    rx.saturating_sub(prev_rx) as f32 / elapsed,
+                    tx.saturating_sub(prev_tx) as f32 / elapsed,
+                )
+            })
+        });
+
+        self.rx_rate = rates.map(|(rx, _)| rx);
+        self.tx_rate = rates.map(|(_, tx)| tx);
+        self.prev = Some((rx, tx, now));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = concat!(
+        "Inter-|   Receive                                                |  Transmit\n",
+        " face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed\n",
+    );
+
+    #[test]
+    fn first_sample_yields_none() {
+        let mut stat = Stat::default();
+        let buf = format!("{HEADER}  eth0: 100 1 0 0 0 0 0 0 200 1 0 0 0 0 0 0\n");
+        stat.process(buf.as_bytes());
+        assert_eq!(stat.rx_rate("eth0"), None);
+        assert_eq!(stat.tx_rate("eth0"), None);
+    }
+
+    #[test]
+    fn unknown_interface_yields_none() {
+        let stat = Stat::default();
+        assert_eq!(stat.rx_rate("eth1"), None);
+    }
+}
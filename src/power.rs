@@ -62,6 +62,44 @@ impl Supply {
         self.open_file("status")
     }
 
+    /// Open the `energy_now` file for this source
+    ///
+    /// The file contains the current energy, in µWh. Present on devices which
+    /// don't expose the `charge_*` interface.
+    pub fn energy_now_file(&self) -> Result<File> {
+        self.open_file("energy_now")
+    }
+
+    /// Open the `energy_full` file for this source
+    ///
+    /// The file contains the energy when the battery is full, in µWh.
+    pub fn energy_full_file(&self) -> Result<File> {
+        self.open_file("energy_full")
+    }
+
+    /// Open the `power_now` file for this source
+    ///
+    /// The file contains the current power draw, in µW.
+    pub fn power_now_file(&self) -> Result<File> {
+        self.open_file("power_now")
+    }
+
+    /// Open the `voltage_now` file for this source
+    ///
+    /// The file contains the current voltage, in µV.
+    pub fn voltage_now_file(&self) -> Result<File> {
+        self.open_file("voltage_now")
+    }
+
+    /// Open the `capacity` file for this source
+    ///
+    /// The file contains a precomputed state-of-charge, in percent. This is
+    /// the only source of state-of-charge on devices exposing neither the
+    /// `charge_*` nor the `energy_*` interface.
+    pub fn capacity_file(&self) -> Result<File> {
+        self.open_file("capacity")
+    }
+
     /// Open a specific file
     fn open_file(&self, name: &str) -> Result<File> {
         self.dir
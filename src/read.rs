@@ -277,6 +277,43 @@ impl Ring {
     }
 }
 
+/// Something that is polled directly rather than through a file descriptor
+///
+/// Unlike [BufProcessor]s, which are fed with the contents of a read [File],
+/// [Poller]s update themselves whenever [Poller::poll] is called. This is
+/// meant for sources which aren't backed by a (cheaply pollable) file, e.g.
+/// ones requiring a dedicated syscall such as `statvfs(2)`.
+pub trait Poller: WantsProcessing {
+    fn poll(&mut self);
+}
+
+/// A (recurring) direct poll of a [Poller]
+///
+/// This is the counterpart to [Item] for [Poller]s: rather than preparing and
+/// processing an IO uring read, it is polled directly from the main loop in
+/// every iteration in which it [WantsProcessing].
+pub struct Poll {
+    poller: Ref<dyn Poller>,
+}
+
+impl Poll {
+    /// Create a new poll item for the given [Poller]
+    pub fn new(poller: Ref<impl Poller + 'static>) -> Self {
+        Self { poller }
+    }
+
+    /// Poll the underlying [Poller]
+    pub fn poll(&self) {
+        self.poller.borrow_mut().poll()
+    }
+}
+
+impl WantsProcessing for Poll {
+    fn wants_processing(&self, before: Instant) -> bool {
+        self.poller.borrow().wants_processing(before)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,6 +40,48 @@ impl<V, S: Scale> Scaled<V, S> {
         }
         Self { value, scale }
     }
+
+    /// Scale the value up or down to keep it within `[min_value, max_value]`
+    ///
+    /// The value is first scaled up as with [Self::max_scale]. If it is
+    /// still below `min_value` afterwards, it is scaled *down* one step at a
+    /// time via [Scale::step_down], multiplying the value by the
+    /// corresponding factor, until it reaches `min_value` or scaling down
+    /// further would push it past `max_value`. A value that is already at or
+    /// below zero is considered to be in range, so it is not scaled down
+    /// indefinitely.
+    pub fn auto_scale<T>(self, min_value: T, max_value: T) -> Self
+    where
+        V: ops::Div<T>
+            + From<<V as ops::Div<T>>::Output>
+            + ops::Mul<T>
+            + From<<V as ops::Mul<T>>::Output>
+            + PartialOrd<T>
+            + Copy,
+        T: ops::Mul<T, Output = T> + From<u16> + Copy,
+    {
+        let Self {
+            mut value,
+            mut scale,
+        } = self.max_scale(min_value);
+
+        if value < min_value && value > T::from(0u16) {
+            while let Some((new_scale, factor)) = scale.step_down() {
+                let factor = T::from(factor.get());
+                let scaled = (value * factor).into();
+                if scaled > max_value {
+                    break;
+                }
+
+                value = scaled;
+                scale = new_scale;
+                if value >= min_value {
+                    break;
+                }
+            }
+        }
+        Self { value, scale }
+    }
 }
 
 impl<V, S: Scale + Default> From<V> for Scaled<V, S> {
@@ -57,6 +99,71 @@ impl<V: fmt::Display, S: Scale + fmt::Display> fmt::Display for Scaled<V, S> {
     }
 }
 
+/// A value decomposed into a series of cascading units (e.g. `1h02m03s`)
+///
+/// Unlike [Scaled], which picks a single largest unit and discards the
+/// remainder, a [Composite] breaks the value down into every unit of the
+/// series up to the largest one that still fits, zero-padding every
+/// component but the leading one.
+#[derive(Copy, Clone)]
+pub struct Composite<V, S: Scale> {
+    value: V,
+    scale: S,
+}
+
+impl<V, S: Scale> Composite<V, S> {
+    /// Create a new composite value, with `value` expressed in `scale`'s unit
+    pub fn new(value: V, scale: S) -> Self {
+        Self { value, scale }
+    }
+}
+
+impl<V, S> fmt::Display for Composite<V, S>
+where
+    V: Copy
+        + PartialOrd<u64>
+        + ops::Div<u64>
+        + From<<V as ops::Div<u64>>::Output>
+        + ops::Rem<u64>
+        + From<<V as ops::Rem<u64>>::Output>
+        + fmt::Display,
+    S: Scale + CompositeUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Cumulative multiplier and unit for each step of the series,
+        // starting at the base unit, plus the per-step factor used to wrap a
+        // component into the next larger unit.
+        let mut units = vec![(self.scale, 1u64)];
+        let mut factors = Vec::new();
+        let mut scale = self.scale;
+        while let Some((next, factor)) = scale.step() {
+            let factor = u64::from(factor.get());
+            factors.push(factor);
+            units.push((next, units.last().expect("at least one unit").1 * factor));
+            scale = next;
+        }
+
+        let top = (0..units.len())
+            .rev()
+            .find(|&i| self.value >= units[i].1)
+            .unwrap_or(0);
+
+        (0..=top).rev().try_for_each(|i| {
+            let (unit, cumulative) = units[i];
+            let unit = unit.composite_unit();
+            let component: V = (self.value / cumulative).into();
+            if i == top {
+                write!(f, "{component}{unit}")
+            } else {
+                let factor = factors[i];
+                let width = (factor - 1).to_string().len();
+                let component: V = (component % factor).into();
+                write!(f, "{component:0width$}{unit}")
+            }
+        })
+    }
+}
+
 /// Trait for scales
 ///
 /// This trait allows abstracting over (unit) scales, such as SI prefixes. Types
@@ -68,6 +175,28 @@ pub trait Scale: Copy {
     /// factor of the current item to the next one. If the current scale is
     /// already the largest one, this function returns `None`.
     fn step(self) -> Option<(Self, NonZeroU16)>;
+
+    /// Get the next smaller scale of this series
+    ///
+    /// The counterpart to [Scale::step]: returns the next smaller scale,
+    /// along with the factor to multiply a value by in order to express it
+    /// in that scale. Defaults to `None`, so scales without sub-unit
+    /// prefixes (such as [BinScale] or [Duration]) are unaffected.
+    fn step_down(self) -> Option<(Self, NonZeroU16)> {
+        None
+    }
+}
+
+/// Trait for scales whose unit can be rendered in a [Composite]
+///
+/// A [Composite] renders every component's unit back to back (e.g.
+/// `1h02m03s`), so it needs a compact label for each one. This is usually,
+/// but not always, the same label used by a scale's standalone
+/// [fmt::Display] impl (e.g. [Duration] spells out `"min"` on its own, but
+/// uses the compact `"m"` as part of a composite).
+pub trait CompositeUnit: Scale {
+    /// Get the compact unit label for this scale
+    fn composite_unit(&self) -> &'static str;
 }
 
 /// Binary scale
@@ -101,14 +230,19 @@ impl Scale for BinScale {
 
 impl fmt::Display for BinScale {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let prefix = match self {
+        f.write_str(self.composite_unit())
+    }
+}
+
+impl CompositeUnit for BinScale {
+    fn composite_unit(&self) -> &'static str {
+        match self {
             Self::Ident => "",
             Self::Kibi => "ki",
             Self::Mebi => "Mi",
             Self::Gibi => "Gi",
             Self::Tebi => "Ti",
-        };
-        f.write_str(prefix)
+        }
     }
 }
 
@@ -150,6 +284,91 @@ impl fmt::Display for Duration {
     }
 }
 
+impl CompositeUnit for Duration {
+    fn composite_unit(&self) -> &'static str {
+        match self {
+            Self::Second => "s",
+            Self::Minute => "m",
+            Self::Hour => "h",
+            Self::Day => "d",
+        }
+    }
+}
+
+/// Decimal SI scale, including sub-unit (fractional) prefixes
+#[derive(Copy, Clone, Debug)]
+pub enum SiScale {
+    Pico,
+    Nano,
+    Micro,
+    Milli,
+    Ident,
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+}
+
+impl Default for SiScale {
+    fn default() -> Self {
+        Self::Ident
+    }
+}
+
+impl Scale for SiScale {
+    fn step(self) -> Option<(Self, NonZeroU16)> {
+        let factor = NonZeroU16::new(1000)?;
+        match self {
+            Self::Pico => Some((Self::Nano, factor)),
+            Self::Nano => Some((Self::Micro, factor)),
+            Self::Micro => Some((Self::Milli, factor)),
+            Self::Milli => Some((Self::Ident, factor)),
+            Self::Ident => Some((Self::Kilo, factor)),
+            Self::Kilo => Some((Self::Mega, factor)),
+            Self::Mega => Some((Self::Giga, factor)),
+            Self::Giga => Some((Self::Tera, factor)),
+            Self::Tera => None,
+        }
+    }
+
+    fn step_down(self) -> Option<(Self, NonZeroU16)> {
+        let factor = NonZeroU16::new(1000)?;
+        match self {
+            Self::Pico => None,
+            Self::Nano => Some((Self::Pico, factor)),
+            Self::Micro => Some((Self::Nano, factor)),
+            Self::Milli => Some((Self::Micro, factor)),
+            Self::Ident => Some((Self::Milli, factor)),
+            Self::Kilo => Some((Self::Ident, factor)),
+            Self::Mega => Some((Self::Kilo, factor)),
+            Self::Giga => Some((Self::Mega, factor)),
+            Self::Tera => Some((Self::Giga, factor)),
+        }
+    }
+}
+
+impl fmt::Display for SiScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.composite_unit())
+    }
+}
+
+impl CompositeUnit for SiScale {
+    fn composite_unit(&self) -> &'static str {
+        match self {
+            Self::Pico => "p",
+            Self::Nano => "n",
+            Self::Micro => "µ",
+            Self::Milli => "m",
+            Self::Ident => "",
+            Self::Kilo => "k",
+            Self::Mega => "M",
+            Self::Giga => "G",
+            Self::Tera => "T",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +389,67 @@ mod tests {
         assert_eq!(scale.to_string(), "256_255")
     }
 
+    #[test]
+    fn auto_scale_up() {
+        let scale = Scaled::new(4_000_000f32, SiScale::default()).auto_scale(1.5, 1000.);
+        assert_eq!(scale.to_string(), "4M")
+    }
+
+    #[test]
+    fn auto_scale_down() {
+        let scale = Scaled::new(0.000_004f32, SiScale::default()).auto_scale(1.5, 1000.);
+        assert_eq!(scale.to_string(), "4µ")
+    }
+
+    #[test]
+    fn auto_scale_in_range() {
+        let scale = Scaled::new(4f32, SiScale::default()).auto_scale(1.5, 1000.);
+        assert_eq!(scale.to_string(), "4")
+    }
+
+    #[test]
+    fn auto_scale_zero() {
+        let scale = Scaled::new(0f32, SiScale::default()).auto_scale(1.5, 1000.);
+        assert_eq!(scale.to_string(), "0")
+    }
+
+    #[test]
+    fn auto_scale_down_exhaust() {
+        // Smaller than even `Pico` can express normally; scaling down stops
+        // once `Pico` is reached, since `step_down` returns `None` there.
+        let scale =
+            Scaled::new(0.000_000_000_000_004f32, SiScale::default()).auto_scale(1.5, 1000.);
+        assert_eq!(scale.to_string(), "0.004p")
+    }
+
+    #[test]
+    fn si_scale_step_down_none_at_pico() {
+        assert!(SiScale::Pico.step_down().is_none())
+    }
+
+    #[test]
+    fn si_scale_step_none_at_tera() {
+        assert!(SiScale::Tera.step().is_none())
+    }
+
+    #[test]
+    fn composite_smoke() {
+        let composite = Composite::new(3723u64, Duration::Second);
+        assert_eq!(composite.to_string(), "1h02m03s")
+    }
+
+    #[test]
+    fn composite_zero() {
+        let composite = Composite::new(0u64, Duration::Second);
+        assert_eq!(composite.to_string(), "0s")
+    }
+
+    #[test]
+    fn composite_exceeds_largest_unit() {
+        let composite = Composite::new(90_000u64, Duration::Second);
+        assert_eq!(composite.to_string(), "1d01h00m00s")
+    }
+
     #[derive(Copy, Clone, PartialEq)]
     struct DummyScale(std::num::NonZeroU8);
 
@@ -28,6 +28,14 @@ pub trait Source {
     {
         Gated::new(self, condition)
     }
+
+    /// Make an [Affine] [Source], scaling and offsetting this source's value
+    fn affine(self, scale: Self::Value, offset: Self::Value) -> Affine<Self>
+    where
+        Self: Sized,
+    {
+        Affine::new(self, scale, offset)
+    }
 }
 
 impl<T: Clone> Source for Option<T> {
@@ -244,6 +252,138 @@ impl<S: WantsProcessing, C: Fn() -> bool> WantsProcessing for Gated<S, C> {
     }
 }
 
+/// A [Source] applying an affine transform (`value * scale + offset`)
+///
+/// Useful for calibrating raw sensor readings or rescaling a counter measured
+/// at one reference rate to another, while preserving the wrapped source's
+/// [Updateable] and [WantsProcessing] behavior.
+pub struct Affine<S: Source> {
+    inner: S,
+    scale: S::Value,
+    offset: S::Value,
+}
+
+impl<S: Source> Affine<S> {
+    /// Create a new [Affine] source from the given `scale` and `offset`
+    pub fn new(source: S, scale: S::Value, offset: S::Value) -> Self {
+        Self {
+            inner: source,
+            scale,
+            offset,
+        }
+    }
+}
+
+impl<S: Source> Source for Affine<S>
+where
+    S::Value: Copy + std::ops::Mul<Output = S::Value> + std::ops::Add<Output = S::Value>,
+{
+    type Value = S::Value;
+
+    fn value(&self) -> Option<Self::Borrow<'_>> {
+        self.inner
+            .value()
+            .map(|v| *v.borrow() * self.scale + self.offset)
+    }
+}
+
+impl<S: Source + Updateable> Updateable for Affine<S> {
+    type Value = <S as Updateable>::Value;
+
+    fn update(&mut self, value: Self::Value) {
+        self.inner.update(value)
+    }
+
+    fn update_invalid(&mut self) {
+        self.inner.update_invalid()
+    }
+}
+
+impl<S: Source + WantsProcessing> WantsProcessing for Affine<S> {
+    fn wants_processing(&self, before: Instant) -> bool {
+        self.inner.wants_processing(before)
+    }
+}
+
+/// A rate of change (derivative) of successive samples
+///
+/// This [Source] retains the two most recent samples with which it was
+/// updated and yields the per-second rate of change between them. Useful for
+/// turning a monotonically increasing counter (bytes transferred, operations
+/// performed, …) into a throughput/rate value.
+pub struct Rate<T> {
+    prev: Option<(T, Instant)>,
+    latest: Option<(T, Instant)>,
+    min_interval: Duration,
+    staleness: Duration,
+}
+
+impl<T> Rate<T> {
+    /// Create a new [Rate], sampling at most once per `min_interval` and
+    /// discarding samples older than `staleness` once invalidated
+    pub fn new(min_interval: Duration, staleness: Duration) -> Self {
+        Self {
+            prev: None,
+            latest: None,
+            min_interval,
+            staleness,
+        }
+    }
+}
+
+impl<T> Source for Rate<T>
+where
+    T: Copy + PartialOrd + std::ops::Sub<Output = T> + std::ops::Div<f32, Output = T>,
+{
+    type Value = T;
+
+    fn value(&self) -> Option<Self::Borrow<'_>> {
+        let (prev, then) = self.prev?;
+        let (latest, now) = self.latest?;
+
+        // A reading smaller than the previous one indicates that the
+        // monitored counter was reset (e.g. a restarted interface), in which
+        // case we can't derive a meaningful rate.
+        if latest < prev {
+            return None;
+        }
+
+        let dt = now.duration_since(then).as_secs_f32();
+        dt.is_normal().then(|| (latest - prev) / dt)
+    }
+}
+
+impl<T: Copy> Updateable for Rate<T> {
+    type Value = T;
+
+    fn update(&mut self, value: Self::Value) {
+        self.prev = self.latest;
+        self.latest = Some((value, Instant::now()));
+    }
+
+    fn update_invalid(&mut self) {
+        // We're fine with a stale latest sample as long as it's recent
+        // enough to still be meaningful once a valid update comes in again.
+        if self
+            .latest
+            .map(|(_, t)| t.elapsed() > self.staleness)
+            .unwrap_or(true)
+        {
+            self.prev = None;
+            self.latest = None;
+        }
+    }
+}
+
+impl<T> WantsProcessing for Rate<T> {
+    fn wants_processing(&self, before: Instant) -> bool {
+        self.latest
+            .as_ref()
+            .map(|(_, l)| before.duration_since(*l) >= self.min_interval)
+            .unwrap_or(true)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,6 +392,106 @@ mod tests {
 
     use mock_instant::global::MockClock;
 
+    #[test]
+    fn affine_scale_offset() {
+        let source = Some(2.0f32).affine(3.0, 1.0);
+        assert_eq!(source.value(), Some(7.0));
+    }
+
+    #[test]
+    fn affine_none() {
+        let source = None::<f32>.affine(3.0, 1.0);
+        assert_eq!(source.value(), None);
+    }
+
+    #[test]
+    fn affine_update_forwards() {
+        let mut source = None::<f32>.affine(2.0, 1.0);
+        source.update(5.0);
+        assert_eq!(source.value(), Some(11.0));
+    }
+
+    #[test]
+    fn affine_update_invalid_forwards() {
+        let mut source = Some(2.0f32).affine(2.0, 1.0);
+        source.update_invalid();
+        assert_eq!(source.value(), None);
+    }
+
+    #[test]
+    fn rate_no_update() {
+        let rate = Rate::<f32>::new(Duration::from_secs(1), Duration::from_secs(30));
+        assert_eq!(rate.value(), None);
+    }
+
+    #[test]
+    fn rate_single_update() {
+        let mut rate = Rate::<f32>::new(Duration::from_secs(1), Duration::from_secs(30));
+        rate.update(10.);
+        assert_eq!(rate.value(), None);
+    }
+
+    #[test]
+    fn rate_basic() {
+        let mut rate = Rate::<f32>::new(Duration::from_secs(1), Duration::from_secs(30));
+        rate.update(0.);
+        MockClock::advance(Duration::from_secs(2));
+        rate.update(20.);
+        assert_eq!(rate.value(), Some(10.));
+    }
+
+    #[test]
+    fn rate_counter_reset() {
+        let mut rate = Rate::<f32>::new(Duration::from_secs(1), Duration::from_secs(30));
+        rate.update(100.);
+        MockClock::advance(Duration::from_secs(2));
+        rate.update(50.);
+        assert_eq!(rate.value(), None);
+    }
+
+    #[test]
+    fn rate_retain_on_short_gap() {
+        let mut rate = Rate::<f32>::new(Duration::from_secs(1), Duration::from_secs(30));
+        rate.update(0.);
+        MockClock::advance(Duration::from_secs(2));
+        rate.update(20.);
+        MockClock::advance(Duration::from_secs(5));
+        rate.update_invalid();
+        assert_eq!(rate.value(), Some(10.));
+    }
+
+    #[test]
+    fn rate_flush_on_long_gap() {
+        let mut rate = Rate::<f32>::new(Duration::from_secs(1), Duration::from_secs(30));
+        rate.update(0.);
+        MockClock::advance(Duration::from_secs(2));
+        rate.update(20.);
+        MockClock::advance(Duration::from_secs(60));
+        rate.update_invalid();
+        assert_eq!(rate.value(), None);
+    }
+
+    #[test]
+    fn rate_wants_processing_initially() {
+        let rate = Rate::<f32>::new(Duration::from_secs(10), Duration::from_secs(30));
+        assert!(rate.wants_processing(Instant::now()));
+    }
+
+    #[test]
+    fn rate_wants_processing_before_interval() {
+        let mut rate = Rate::<f32>::new(Duration::from_secs(10), Duration::from_secs(30));
+        rate.update(0.);
+        assert!(!rate.wants_processing(Instant::now()));
+    }
+
+    #[test]
+    fn rate_wants_processing_after_interval() {
+        let mut rate = Rate::<f32>::new(Duration::from_secs(10), Duration::from_secs(30));
+        rate.update(0.);
+        MockClock::advance(Duration::from_secs(10));
+        assert!(rate.wants_processing(Instant::now()));
+    }
+
     #[test]
     fn moving_average_no_update() {
         let avg = MovingAverage::<f32>::new(Duration::from_secs(5));
@@ -3,6 +3,7 @@
 //! Status line specification helpers
 
 use std::collections::hash_map::{self, HashMap};
+use std::ffi::CString;
 use std::fmt;
 use std::fs::File;
 use std::os::linux::fs::MetadataExt;
@@ -12,22 +13,30 @@ use std::time::Duration;
 
 use anyhow::{Context, Result};
 
+use crate::cpu;
+use crate::disk;
 use crate::entry::{self, Entry};
 use crate::meminfo;
+use crate::net;
 use crate::power;
 use crate::read;
 use crate::scale;
 use crate::source::{self, LowerRate};
+use crate::thermal;
 
 /// Base interval for updates
 const BASE_INTERVAL: Duration = Duration::from_secs(5);
 
 /// Create entries based on command line arguments
 ///
-/// Associated [read::Item]s will be appended to `items`.
-pub fn entries(items: &mut Vec<read::Item>) -> Result<Vec<Box<dyn fmt::Display>>> {
+/// Associated [read::Item]s will be appended to `items`, [read::Poll]s (for
+/// sources not backed by a file descriptor) to `polls`.
+pub fn entries(
+    items: &mut Vec<read::Item>,
+    polls: &mut Vec<read::Poll>,
+) -> Result<Vec<Box<dyn fmt::Display>>> {
     let mut res = Default::default();
-    let mut items = ReadItemInstaller::new(items);
+    let mut items = ReadItemInstaller::new(items, polls);
     std::env::args().skip(1).try_for_each(|a| {
         apply(a.as_str().into(), &mut res, &mut items)
             .with_context(|| format!("Could not add entries for '{a}'"))
@@ -45,19 +54,71 @@ fn apply(
     installer: &mut ReadItemInstaller<'_>,
 ) -> Result<()> {
     match spec.main {
-        "datetime" | "time" | "dt" | "t" => {
-            spec.no_subs()?;
-            entries.push(entry::LocalTime.into_fmt());
-            Ok(())
-        }
+        "datetime" | "time" | "dt" | "t" => apply_datetime(spec, entries),
+        "cpu" | "c" => apply_cpu(spec, entries, installer),
         "load" | "l" => apply_load(spec, entries, installer),
         "pressure" | "pres" | "psi" | "p" => apply_psi(spec, entries, installer),
         "memory" | "mem" | "m" => apply_meminfo(spec, entries, installer),
         "battery" | "bat" | "b" => apply_battery(spec, entries, installer),
+        "thermal" | "temp" | "T" => apply_thermal(spec, entries, installer),
+        "net" | "network" | "n" => apply_net(spec, entries, installer),
+        "disk" | "fs" | "d" => apply_disk(spec, entries, installer),
         _ => anyhow::bail!("Unknown main spec: '{}'", spec.main),
     }
 }
 
+/// Aplly a datetime [Spec]
+///
+/// A leading `utc` sub-spec selects [entry::UtcTime] over [entry::LocalTime].
+/// Any remaining subs are re-joined with `,` (the character sub-specs are
+/// themselves split on) and used as an strftime(3)-style format string.
+fn apply_datetime(spec: Spec<'_>, entries: &mut Vec<Box<dyn fmt::Display>>) -> Result<()> {
+    let utc = spec.subs.first() == Some(&"utc");
+    let format = if utc { &spec.subs[1..] } else { &spec.subs[..] };
+    let format = (!format.is_empty())
+        .then(|| CString::new(format.join(",")))
+        .transpose()
+        .context("Datetime format must not contain a NUL byte")?;
+
+    let entry = match (utc, format) {
+        (true, Some(fmt)) => entry::UtcTime::with_format(fmt).into_fmt(),
+        (true, None) => entry::UtcTime::default().into_fmt(),
+        (false, Some(fmt)) => entry::LocalTime::with_format(fmt).into_fmt(),
+        (false, None) => entry::LocalTime::default().into_fmt(),
+    };
+    entries.push(entry);
+    Ok(())
+}
+
+/// Aplly a CPU utilization [Spec]
+fn apply_cpu(
+    spec: Spec<'_>,
+    entries: &mut Vec<Box<dyn fmt::Display>>,
+    installer: &mut ReadItemInstaller<'_>,
+) -> Result<()> {
+    let source = installer.default::<cpu::Stat>("/proc/stat", 8192)?;
+    let default = ["cpu"];
+    let cores: &[&str] = if spec.subs.is_empty() {
+        &default
+    } else {
+        &spec.subs
+    };
+
+    cores.iter().for_each(|&core| {
+        let core = core.to_owned();
+        let entry = entry::mapped(source.clone(), {
+            let core = core.clone();
+            move |s: &cpu::Stat| s.usage(&core)
+        })
+        .with_precision(0)
+        .with_unit('%')
+        .into_fmt();
+        entries.push(entry::label(core));
+        entries.push(entry);
+    });
+    Ok(())
+}
+
 /// Aplly a load [Spec]
 fn apply_load(
     spec: Spec<'_>,
@@ -119,6 +180,31 @@ fn apply_meminfo(
         })
 }
 
+/// Try to open the charge-based (µAh/µA) battery files
+///
+/// Returns the `charge_now`, `charge_full` and `current_now` files, in that
+/// order, if all three are present.
+fn charge_files(p: &power::Supply) -> Option<(File, File, File)> {
+    Some((
+        p.charge_now_file().ok()?,
+        p.charge_full_file().ok()?,
+        p.current_now_file().ok()?,
+    ))
+}
+
+/// Try to open the energy-based (µWh/µW) battery files
+///
+/// Returns the `energy_now`, `energy_full` and `power_now` files, in that
+/// order, if all three are present. Devices not exposing the `charge_*`
+/// interface tend to expose this one instead.
+fn energy_files(p: &power::Supply) -> Option<(File, File, File)> {
+    Some((
+        p.energy_now_file().ok()?,
+        p.energy_full_file().ok()?,
+        p.power_now_file().ok()?,
+    ))
+}
+
 /// Aplly a battery [Spec]
 fn apply_battery(
     spec: Spec<'_>,
@@ -133,59 +219,213 @@ fn apply_battery(
         .filter_map(Result::ok)
         .filter(|p| p.kind().ok() == Some(power::Kind::Battery))
         .try_for_each(|p| {
-            let full = Simple::new(
-                LowerRate::new(Duration::from_secs(120)),
-                u8::is_ascii_whitespace,
-            );
-            let full = installer.install_file(p.charge_full_file()?, 16, full)?;
-            let now = Simple::new(
-                LowerRate::new(Duration::from_secs(15)),
-                u8::is_ascii_whitespace,
-            );
-            let now = installer.install_file(p.charge_now_file()?, 16, now)?;
-            let soc = entry::zipped(full, now.clone(), |f: &f32, n: &f32| Some(100. * n / f))
-                .with_precision(0)
-                .with_unit('%')
-                .into_fmt();
-
             let status = installer.install_file(
                 p.status_file()?,
                 16,
                 Simple::new(LowerRate::new(BASE_INTERVAL), u8::is_ascii_control),
             )?;
-            let avg = MovingAverage::<f32>::new(Duration::from_secs(60)).gated_with({
-                let status = status.clone();
-                move || status.borrow().value() == Some(Status::Discharging)
-            });
-            let current = installer.install_file(
-                p.current_now_file()?,
-                16,
-                Simple::new(avg, u8::is_ascii_whitespace),
-            )?;
-            let status = move || {
-                let status = status.borrow().value()?;
-                let display = (status == Status::Discharging)
-                    .then(|| {
-                        let charge = now.borrow().value();
-                        let current = current.borrow().value().filter(|c| c.is_normal());
-                        Option::zip(current, charge)
-                    })
-                    .flatten()
-                    .map(|(i, c)| c * 3600. / i) // µAh * s/h / µA
-                    .autoscaled(1.5, scale::Duration::Second)
-                    .with_precision(1)
-                    .display()
-                    .map_or(either::Either::Left(status.symbol()), either::Either::Right);
-                Some(display)
-            };
 
             entries.push(entry::label(p.name().to_owned()));
-            entries.push(soc);
-            entries.push(status.into_fmt());
+
+            // Devices usually expose either the charge-based (µAh/µA) or the
+            // energy-based (µWh/µW) interface. The math for state-of-charge
+            // and time-remaining is identical either way, since it only ever
+            // relies on the now/full ratio and on now/rate or (full - now)/rate.
+            if let Some((now_file, full_file, rate_file)) =
+                charge_files(&p).or_else(|| energy_files(&p))
+            {
+                let full = Simple::new(
+                    LowerRate::new(Duration::from_secs(120)),
+                    u8::is_ascii_whitespace,
+                );
+                let full = installer.install_file(full_file, 16, full)?;
+                let now = Simple::new(
+                    LowerRate::new(Duration::from_secs(15)),
+                    u8::is_ascii_whitespace,
+                );
+                let now = installer.install_file(now_file, 16, now)?;
+                let soc = entry::zipped(full.clone(), now.clone(), |f: &f32, n: &f32| {
+                    Some(100. * n / f)
+                })
+                .with_precision(0)
+                .with_unit('%')
+                .into_fmt();
+
+                let avg = MovingAverage::<f32>::new(Duration::from_secs(60)).gated_with({
+                    let status = status.clone();
+                    move || {
+                        matches!(
+                            status.borrow().value(),
+                            Some(Status::Discharging) | Some(Status::Charging)
+                        )
+                    }
+                });
+                let rate = installer.install_file(
+                    rate_file,
+                    16,
+                    Simple::new(avg, u8::is_ascii_whitespace),
+                )?;
+                let status = move || {
+                    let status = status.borrow().value()?;
+                    let rate = rate.borrow().value().filter(|c| c.is_normal());
+                    let eta = match status {
+                        Status::Discharging => {
+                            Option::zip(rate, now.borrow().value()).map(|(i, c)| c * 3600. / i)
+                        }
+                        Status::Charging => {
+                            Option::zip(rate, Option::zip(full.borrow().value(), now.borrow().value()))
+                                .map(|(i, (f, c))| (f - c) * 3600. / i)
+                        }
+                        _ => None,
+                    };
+                    let display = eta // [µAh|µWh] * s/h / [µA|µW]
+                        .autoscaled(1.5, scale::Duration::Second)
+                        .with_precision(1)
+                        .display()
+                        .map_or(either::Either::Left(status.symbol()), either::Either::Right);
+                    Some(display)
+                };
+
+                entries.push(soc);
+                entries.push(status.into_fmt());
+            } else {
+                // Neither interface is present; fall back to the
+                // precomputed `capacity` file. Without a rate file there is
+                // no way to estimate a time remaining.
+                let capacity = Simple::new(
+                    LowerRate::<f32>::new(Duration::from_secs(60)),
+                    u8::is_ascii_whitespace,
+                );
+                let capacity = installer.install_file(p.capacity_file()?, 16, capacity)?;
+                let soc = capacity.with_precision(0).with_unit('%').into_fmt();
+                let status = move || status.borrow().value().map(Status::symbol);
+
+                entries.push(soc);
+                entries.push(status.into_fmt());
+            }
+            Ok(())
+        })
+}
+
+/// Aplly a thermal [Spec]
+fn apply_thermal(
+    spec: Spec<'_>,
+    entries: &mut Vec<Box<dyn fmt::Display>>,
+    installer: &mut ReadItemInstaller<'_>,
+) -> Result<()> {
+    thermal::sensors()?
+        .filter_map(Result::ok)
+        .filter(|s| {
+            spec.subs.is_empty()
+                || spec
+                    .subs
+                    .iter()
+                    .any(|&sub| sub == s.chip() || Some(sub) == s.label())
+        })
+        .try_for_each(|s| {
+            let read = read::Simple::new(
+                LowerRate::<f64>::new(BASE_INTERVAL),
+                u8::is_ascii_whitespace,
+            );
+            let source = installer.install_file(s.input_file()?, 16, read)?;
+            let entry = entry::mapped(source, |m: &f64| Some(m / 1000.))
+                .with_precision(1)
+                .with_unit('°')
+                .into_fmt();
+
+            entries.push(entry::label(s.label().unwrap_or_else(|| s.chip()).to_owned()));
+            entries.push(entry);
             Ok(())
         })
 }
 
+/// Aplly a network throughput [Spec]
+fn apply_net(
+    spec: Spec<'_>,
+    entries: &mut Vec<Box<dyn fmt::Display>>,
+    installer: &mut ReadItemInstaller<'_>,
+) -> Result<()> {
+    let source = installer.default::<net::Stat>("/proc/net/dev", 8192)?;
+
+    let ifaces: Vec<String> = if spec.subs.is_empty() {
+        net::interfaces()?
+            .into_iter()
+            .filter(|i| i != "lo")
+            .collect()
+    } else {
+        spec.subs.iter().map(|&s| s.to_owned()).collect()
+    };
+
+    ifaces.into_iter().for_each(|iface| {
+        let rx = entry::mapped(source.clone(), {
+            let iface = iface.clone();
+            move |s: &net::Stat| s.rx_rate(&iface)
+        })
+        .autoscaled(1.5, scale::BinScale::default())
+        .with_precision(1)
+        .with_unit('B')
+        .into_fmt();
+        let tx = entry::mapped(source.clone(), {
+            let iface = iface.clone();
+            move |s: &net::Stat| s.tx_rate(&iface)
+        })
+        .autoscaled(1.5, scale::BinScale::default())
+        .with_precision(1)
+        .with_unit('B')
+        .into_fmt();
+
+        entries.push(entry::label(iface));
+        entries.push(rx);
+        entries.push(tx);
+    });
+    Ok(())
+}
+
+/// Aplly a disk usage [Spec]
+fn apply_disk(
+    spec: Spec<'_>,
+    entries: &mut Vec<Box<dyn fmt::Display>>,
+    installer: &mut ReadItemInstaller<'_>,
+) -> Result<()> {
+    spec.parsed_subs_or([Ok(DiskSpec {
+        path: "/".into(),
+        percent: false,
+    })])
+    .try_for_each(|d| {
+        let d: DiskSpec = d?;
+        let mount = installer.poll(disk::Mount::new(d.path.clone(), BASE_INTERVAL));
+
+        entries.push(entry::label(d.path));
+        if d.percent {
+            let entry = entry::mapped(mount, |u: &disk::Usage| Some(u.percent_used()))
+                .with_precision(0)
+                .with_unit('%')
+                .into_fmt();
+            entries.push(entry);
+        } else {
+            let used = entry::mapped(mount.clone(), |u: &disk::Usage| Some(u.used() as f64))
+                .autoscaled(1.5, scale::BinScale::default())
+                .with_precision(1)
+                .with_unit('B')
+                .into_fmt();
+            let free = entry::mapped(mount.clone(), |u: &disk::Usage| Some(u.free() as f64))
+                .autoscaled(1.5, scale::BinScale::default())
+                .with_precision(1)
+                .with_unit('B')
+                .into_fmt();
+            let total = entry::mapped(mount, |u: &disk::Usage| Some(u.total() as f64))
+                .autoscaled(1.5, scale::BinScale::default())
+                .with_precision(1)
+                .with_unit('B')
+                .into_fmt();
+            entries.push(used);
+            entries.push(free);
+            entries.push(total);
+        }
+        Ok(())
+    })
+}
+
 /// A single specification for status line entries
 #[derive(PartialEq, Debug)]
 struct Spec<'a> {
@@ -276,21 +516,59 @@ impl fmt::Display for PSI {
     }
 }
 
+/// Disk usage sub specification
+///
+/// A mount point, optionally suffixed with `%` to request percent-used
+/// rather than the used/free/total breakdown.
+#[derive(Clone, Debug)]
+struct DiskSpec {
+    path: String,
+    percent: bool,
+}
+
+impl FromStr for DiskSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_suffix('%') {
+            Some(path) => Ok(Self {
+                path: path.to_owned(),
+                percent: true,
+            }),
+            None => Ok(Self {
+                path: s.to_owned(),
+                percent: false,
+            }),
+        }
+    }
+}
+
 /// Installer for [read::Item]s, making sure we only have one per path
 struct ReadItemInstaller<'i> {
     items: &'i mut Vec<read::Item>,
+    polls: &'i mut Vec<read::Poll>,
     processors: HashMap<(u64, u64), std::rc::Rc<dyn std::any::Any>>,
 }
 
 impl<'i> ReadItemInstaller<'i> {
-    /// Create a new installer pusing [read::Item]s to the given [Vec]
-    pub fn new(items: &'i mut Vec<read::Item>) -> Self {
+    /// Create a new installer pusing [read::Item]s and [read::Poll]s to the
+    /// given [Vec]s
+    pub fn new(items: &'i mut Vec<read::Item>, polls: &'i mut Vec<read::Poll>) -> Self {
         Self {
             items,
+            polls,
             processors: Default::default(),
         }
     }
 
+    /// Install a [read::Poller], polled directly from the main loop whenever
+    /// it wants processing
+    pub fn poll<P: read::Poller + 'static>(&mut self, poller: P) -> read::Ref<P> {
+        let poller: read::Ref<P> = read::Ref::new(poller.into());
+        self.polls.push(read::Poll::new(poller.clone()));
+        poller
+    }
+
     /// Install a [read::BufProcessor]'s [Default] value
     pub fn default<P: read::BufProcessor + Default + 'static>(
         &mut self,
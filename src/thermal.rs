@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+// Copyright Julian Ganz 2024
+//! Uitilities related to entities in `/sys/class/hwmon`
+
+use std::fs::File;
+use std::io::Read;
+use std::rc::Rc;
+
+use anyhow::{Context, Result};
+
+/// Representation of a temperature sensor in `/sys/class/hwmon/hwmonN`
+pub struct Sensor {
+    chip: String,
+    label: Option<String>,
+    dir: Rc<openat::Dir>,
+    index: u32,
+}
+
+impl Sensor {
+    /// Get the name of the chip this sensor belongs to
+    pub fn chip(&self) -> &str {
+        self.chip.as_ref()
+    }
+
+    /// Get the label of this sensor, if any
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Open the `tempN_input` file for this sensor
+    ///
+    /// The file contains the current temperature, in millidegrees Celsius.
+    pub fn input_file(&self) -> Result<File> {
+        self.open_file(&format!("temp{}_input", self.index))
+    }
+
+    /// Open the `tempN_max` file for this sensor
+    ///
+    /// The file contains the maximum temperature, in millidegrees Celsius.
+    pub fn max_file(&self) -> Result<File> {
+        self.open_file(&format!("temp{}_max", self.index))
+    }
+
+    /// Open the `tempN_crit` file for this sensor
+    ///
+    /// The file contains the critical temperature, in millidegrees Celsius.
+    pub fn crit_file(&self) -> Result<File> {
+        self.open_file(&format!("temp{}_crit", self.index))
+    }
+
+    /// Open a specific file
+    fn open_file(&self, name: &str) -> Result<File> {
+        self.dir
+            .open_file(name)
+            .with_context(|| format!("Could not open '{name}'"))
+    }
+}
+
+/// Get all temperature sensors
+pub fn sensors() -> Result<impl Iterator<Item = Result<Sensor>>> {
+    let list = std::fs::read_dir("/sys/class/hwmon/")
+        .context("Could not access /sys/class/hwmon/")?
+        .flat_map(|e| match discover(e) {
+            Ok(sensors) => either::Either::Left(sensors.into_iter().map(Ok)),
+            Err(err) => either::Either::Right(std::iter::once(Err(err))),
+        });
+    Ok(list)
+}
+
+/// Discover all [Sensor]s in a single `hwmonN` directory
+fn discover(entry: std::io::Result<std::fs::DirEntry>) -> Result<Vec<Sensor>> {
+    let path = entry.context("Could not read entry")?.path();
+    let dir = openat::Dir::open(&path)
+        .with_context(|| format!("Could not open dir {}", path.display()))?;
+    let dir = Rc::new(dir);
+
+    let mut chip = String::new();
+    dir.open_file("name")
+        .context("Could not open 'name'")?
+        .read_to_string(&mut chip)
+        .context("Could not read chip name")?;
+    let chip = chip.trim().to_owned();
+
+    std::fs::read_dir(&path)
+        .with_context(|| format!("Could not list dir {}", path.display()))?
+        .filter_map(Result::ok)
+        .filter_map(|e| e.file_name().into_string().ok())
+        .filter_map(|n| n.strip_prefix("temp")?.strip_suffix("_input")?.parse().ok())
+        .map(|index: u32| {
+            let mut label = String::new();
+            let has_label = dir
+                .open_file(format!("temp{index}_label"))
+                .ok()
+                .map(|mut f| f.read_to_string(&mut label))
+                .transpose()
+                .context("Could not read sensor label")?
+                .is_some();
+            Ok(Sensor {
+                chip: chip.clone(),
+                label: has_label.then(|| label.trim().to_owned()),
+                dir: dir.clone(),
+                index,
+            })
+        })
+        .collect()
+}